@@ -1,7 +1,7 @@
 """Context management API endpoints."""
 
 import time
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
 from typing import List, Optional
 
 from fastapi import APIRouter, Depends, HTTPException, Query
@@ -27,6 +27,33 @@ from ..schemas import (
 router = APIRouter()
 
 
+def _to_naive_utc(value: Optional[datetime]) -> Optional[datetime]:
+    """Convert an aware timestamp to naive UTC, matching how created_at is stored."""
+    if value is not None and value.tzinfo is not None:
+        return value.astimezone(timezone.utc).replace(tzinfo=None)
+    return value
+
+
+def _apply_date_range(query, created_after: Optional[datetime], created_before: Optional[datetime]):
+    """Restrict a ContextEntry query to a creation-date window."""
+    created_after = _to_naive_utc(created_after)
+    created_before = _to_naive_utc(created_before)
+    
+    if created_after and created_before and created_after >= created_before:
+        raise HTTPException(
+            status_code=400,
+            detail="created_after must be earlier than created_before"
+        )
+    
+    if created_after:
+        query = query.filter(ContextEntry.created_at >= created_after)
+    
+    if created_before:
+        query = query.filter(ContextEntry.created_at < created_before)
+    
+    return query
+
+
 @router.get("/", response_model=ContextQueryResponse)
 async def get_context_entries(
     model: Optional[str] = Query(None, description="AI model requesting context"),
@@ -35,7 +62,10 @@ async def get_context_entries(
     source: Optional[str] = Query(None, description="Filter by source pattern"),
     limit: int = Query(50, ge=1, le=1000, description="Maximum number of entries"),
     offset: int = Query(0, ge=0, description="Number of entries to skip"),
-    since: Optional[datetime] = Query(None, description="Only entries after this timestamp"),
+    since: Optional[datetime] = Query(None, description="Deprecated alias of created_after; ignored when created_after is given"),
+    created_after: Optional[datetime] = Query(None, description="Only entries created at or after this timestamp"),
+    created_before: Optional[datetime] = Query(None, description="Only entries created before this timestamp"),
+    injection_enabled: Optional[bool] = Query(None, description="Filter by whether entries may be injected"),
     include_metadata: bool = Query(False, description="Include full metadata"),
     search: Optional[str] = Query(None, description="Search query"),
     db: Session = Depends(get_db_session)
@@ -57,9 +87,6 @@ async def get_context_entries(
     if context_types:
         filters.append(ContextEntry.context_type.in_(context_types))
     
-    if since:
-        filters.append(ContextEntry.created_at >= since)
-    
     if source:
         filters.append(ContextEntry.source.ilike(f"%{source}%"))
     
@@ -74,9 +101,14 @@ async def get_context_entries(
         # Simple text search in content
         filters.append(ContextEntry.content.ilike(f"%{search}%"))
     
+    if injection_enabled is not None:
+        filters.append(ContextEntry.injection_enabled_clause(injection_enabled))
+    
     if filters:
         query = query.filter(and_(*filters))
     
+    query = _apply_date_range(query, created_after or since, created_before)
+    
     # Apply permission checks if model is specified
     if model:
         # TODO: Implement permission checking service
@@ -200,6 +232,32 @@ async def update_context_entry(
         raise HTTPException(status_code=400, detail=f"Failed to update context entry: {str(e)}")
 
 
+@router.put("/{entry_id}/injection", response_model=ContextEntryResponse)
+async def set_context_entry_injection(
+    entry_id: str,
+    enabled: bool = Query(..., description="Whether the entry may be injected into prompts"),
+    db: Session = Depends(get_db_session)
+):
+    """Enable or disable injection of a specific context entry."""
+    entry = db.query(ContextEntry).filter(ContextEntry.id == entry_id).first()
+    
+    if not entry:
+        raise HTTPException(status_code=404, detail="Context entry not found")
+    
+    try:
+        entry.set_injection_enabled(enabled)
+        entry.updated_at = datetime.utcnow()
+        
+        db.commit()
+        db.refresh(entry)
+        
+        return ContextEntryResponse.from_orm(entry)
+        
+    except Exception as e:
+        db.rollback()
+        raise HTTPException(status_code=400, detail=f"Failed to update context entry: {str(e)}")
+
+
 @router.delete("/{entry_id}", response_model=SuccessResponse)
 async def delete_context_entry(
     entry_id: str,
@@ -233,6 +291,8 @@ async def search_context_entries(
     model: Optional[str] = Query(None, description="AI model requesting context"),
     context_types: Optional[List[str]] = Query(None),
     tags: Optional[List[str]] = Query(None),
+    created_after: Optional[datetime] = Query(None, description="Only entries created at or after this timestamp"),
+    created_before: Optional[datetime] = Query(None, description="Only entries created before this timestamp"),
     db: Session = Depends(get_db_session)
 ):
     """Search context entries by content."""
@@ -264,6 +324,8 @@ async def search_context_entries(
             tag_conditions.append(ContextEntry.tags.contains([tag]))
         search_query = search_query.filter(or_(*tag_conditions))
     
+    search_query = _apply_date_range(search_query, created_after, created_before)
+    
     # Apply permission checks if model is specified
     if model:
         # TODO: Implement permission checking
@@ -339,6 +401,15 @@ async def get_context_stats(db: Session = Depends(get_db_session)):
     oldest_entry = db.query(func.min(ContextEntry.created_at)).scalar()
     newest_entry = db.query(func.max(ContextEntry.created_at)).scalar()
     
+    # Recently added entries
+    now = datetime.utcnow()
+    entries_this_week = _apply_date_range(
+        db.query(ContextEntry), now - timedelta(days=7), None
+    ).count()
+    entries_this_month = _apply_date_range(
+        db.query(ContextEntry), now - timedelta(days=30), None
+    ).count()
+    
     # Character count
     total_chars = db.query(func.sum(func.length(ContextEntry.content))).scalar() or 0
     
@@ -361,6 +432,8 @@ async def get_context_stats(db: Session = Depends(get_db_session)):
         recent_entries=recent_brief,
         oldest_entry=oldest_entry,
         newest_entry=newest_entry,
+        entries_this_week=entries_this_week,
+        entries_this_month=entries_this_month,
         total_characters=total_chars,
         average_access_count=float(avg_access),
     )
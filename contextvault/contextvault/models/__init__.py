@@ -1,6 +1,6 @@
 """Database models for ContextVault."""
 
-from .context import ContextEntry, ContextType
+from .context import ContextEntry, ContextType, NO_INJECT_TAG
 from .permissions import Permission
 from .sessions import Session
 from .mcp import MCPConnection, MCPProvider, MCPConnectionStatus, MCPProviderType
@@ -8,6 +8,7 @@ from .mcp import MCPConnection, MCPProvider, MCPConnectionStatus, MCPProviderTyp
 __all__ = [
     "ContextEntry",
     "ContextType", 
+    "NO_INJECT_TAG",
     "Permission",
     "Session",
     "MCPConnection",
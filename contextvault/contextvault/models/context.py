@@ -5,12 +5,16 @@ import uuid
 from datetime import datetime
 from typing import Any, Dict, List, Optional
 
-from sqlalchemy import JSON, DateTime, Enum, String, Text, func, LargeBinary
+from sqlalchemy import JSON, DateTime, Enum, String, Text, func, LargeBinary, not_, or_
 from sqlalchemy.orm import Mapped, mapped_column
 
 from ..database import Base
 
 
+# Reserved tag that keeps an entry in the vault but out of prompt injection
+NO_INJECT_TAG = "no-inject"
+
+
 class ContextType(str, Enum):
     """Enumeration of supported context types."""
     TEXT = "text"
@@ -286,6 +290,27 @@ class ContextEntry(Base):
         """Check if this context entry has a specific tag."""
         return self.tags is not None and tag in self.tags
     
+    @property
+    def injection_enabled(self) -> bool:
+        """Whether this entry may be injected into prompts."""
+        return not self.has_tag(NO_INJECT_TAG)
+    
+    @classmethod
+    def injection_enabled_clause(cls, enabled: bool = True):
+        """SQL condition matching entries whose injection_enabled equals ``enabled``."""
+        muted = cls.tags.op('LIKE')(f'%"{NO_INJECT_TAG}"%')
+        if enabled:
+            return or_(cls.tags.is_(None), not_(muted))
+        return muted
+    
+    def set_injection_enabled(self, enabled: bool) -> None:
+        """Allow or prevent injection of this entry into prompts."""
+        tags = [tag for tag in (self.tags or []) if tag != NO_INJECT_TAG]
+        if not enabled:
+            tags.append(NO_INJECT_TAG)
+        # Reassign so SQLAlchemy notices the JSON column changed
+        self.tags = tags
+    
     def update_metadata(self, key: str, value: Any) -> None:
         """Update a metadata field."""
         if self.entry_metadata is None:
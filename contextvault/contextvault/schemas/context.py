@@ -41,6 +41,7 @@ class ContextEntryResponse(ContextEntryBase):
     access_count: int = Field(default=0, description="Number of times accessed")
     last_accessed_at: Optional[datetime] = Field(None, description="Last access timestamp")
     relevance_score: Optional[float] = Field(None, description="Relevance score")
+    injection_enabled: bool = Field(default=True, description="Whether the entry may be injected into prompts")
     
     model_config = ConfigDict(
         from_attributes=True,
@@ -107,6 +108,8 @@ class ContextStats(BaseModel):
     recent_entries: List[ContextEntryBrief] = Field(..., description="Recent entries")
     oldest_entry: Optional[datetime] = Field(None, description="Oldest entry timestamp")
     newest_entry: Optional[datetime] = Field(None, description="Newest entry timestamp")
+    entries_this_week: int = Field(default=0, description="Entries created in the last 7 days")
+    entries_this_month: int = Field(default=0, description="Entries created in the last 30 days")
     total_characters: int = Field(default=0, description="Total character count")
     average_access_count: float = Field(default=0.0, description="Average access count")
     
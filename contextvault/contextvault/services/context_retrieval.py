@@ -94,6 +94,9 @@ class ContextRetrievalService:
             if max_age_days:
                 filters["since"] = datetime.utcnow() - timedelta(days=max_age_days)
             
+            # Entries muted for injection must not take up candidate slots
+            filters["injection_enabled"] = True
+            
             # Perform initial retrieval using semantic search if available
             print(f"[CONTEXT RETRIEVAL DEBUG] query_context: '{query_context}'")
             print(f"[CONTEXT RETRIEVAL DEBUG] query_context is None: {query_context is None}")
@@ -115,7 +118,8 @@ class ContextRetrievalService:
                         semantic_results = semantic_service.search_with_hybrid_scoring(
                             query=query_context.strip(),
                             db_session=self.db_session,
-                            max_results=limit * 2
+                            max_results=limit * 2,
+                            injectable_only=True
                         )
                         print(f"[CONTEXT RETRIEVAL DEBUG] Semantic search returned {len(semantic_results)} results")
                     except Exception as e:
@@ -145,6 +149,7 @@ class ContextRetrievalService:
                             limit=limit * 2,
                             context_types=context_types,
                             tags=tags,
+                            injection_enabled=True,
                         )
                         print(f"[CONTEXT RETRIEVAL DEBUG] Vault service returned {len(entries)} entries, total: {total}")
                     except Exception as e:
@@ -212,7 +212,8 @@ class ContextPipelineDebugger:
                 semantic_results = semantic_service.search_with_hybrid_scoring(
                     query=user_prompt,
                     db_session=db,
-                    max_results=10
+                    max_results=10,
+                    injectable_only=True
                 )
                 
                 step_duration = (time.time() - step_start) * 1000
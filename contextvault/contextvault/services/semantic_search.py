@@ -313,11 +313,14 @@ class SemanticSearchService:
         query: str,
         db_session: Session,
         max_results: Optional[int] = None,
-        similarity_threshold: Optional[float] = None
+        similarity_threshold: Optional[float] = None,
+        injectable_only: bool = False
     ) -> List[Tuple[ContextEntry, float]]:
         """
         Search for context entries similar to the query.
         
+        Entries muted for injection are skipped when injectable_only is set.
+        
         Returns:
             List of (ContextEntry, similarity_score) tuples, sorted by relevance
         """
@@ -331,7 +334,9 @@ class SemanticSearchService:
         try:
             # Use fallback TF-IDF if transformers not available
             if hasattr(self, 'fallback_mode') and self.fallback_mode:
-                return self._search_with_tfidf(query, db_session, max_results, similarity_threshold)
+                return self._search_with_tfidf(
+                    query, db_session, max_results, similarity_threshold, injectable_only
+                )
             
             # Generate query embedding
             query_embedding = self.generate_embedding(query)
@@ -343,7 +348,10 @@ class SemanticSearchService:
             self.update_context_embeddings(db_session)
             
             # Get all context entries
-            all_entries = db_session.query(ContextEntry).all()
+            entries_query = db_session.query(ContextEntry)
+            if injectable_only:
+                entries_query = entries_query.filter(ContextEntry.injection_enabled_clause())
+            all_entries = entries_query.all()
             
             if not all_entries:
                 return []
@@ -380,7 +388,8 @@ class SemanticSearchService:
         query: str,
         db_session: Session,
         max_results: Optional[int] = None,
-        similarity_threshold: Optional[float] = None
+        similarity_threshold: Optional[float] = None,
+        injectable_only: bool = False
     ) -> List[Tuple[ContextEntry, float]]:
         """Fallback search using keyword matching."""
         max_results = max_results or self.max_results
@@ -388,7 +397,10 @@ class SemanticSearchService:
         
         try:
             # Get all context entries
-            all_entries = db_session.query(ContextEntry).all()
+            entries_query = db_session.query(ContextEntry)
+            if injectable_only:
+                entries_query = entries_query.filter(ContextEntry.injection_enabled_clause())
+            all_entries = entries_query.all()
             
             if not all_entries:
                 logger.info("No context entries found for keyword search")
@@ -454,11 +466,14 @@ class SemanticSearchService:
         max_results: Optional[int] = None,
         semantic_weight: float = 0.6,
         recency_weight: float = 0.3,
-        frequency_weight: float = 0.1
+        frequency_weight: float = 0.1,
+        injectable_only: bool = False
     ) -> List[Tuple[ContextEntry, float, Dict[str, float]]]:
         """
         Search with hybrid scoring combining semantic similarity, recency, and access frequency.
         
+        Entries muted for injection are skipped when injectable_only is set.
+        
         Returns:
             List of (ContextEntry, total_score, score_breakdown) tuples
         """
@@ -470,7 +485,8 @@ class SemanticSearchService:
         try:
             # Get semantic similarities
             semantic_results = self.search_similar_contexts(
-                query, db_session, max_results=100  # Get more candidates for reranking
+                query, db_session, max_results=100,  # Get more candidates for reranking
+                injectable_only=injectable_only
             )
             
             if not semantic_results:
@@ -176,6 +176,10 @@ class VaultService:
             if "until" in filters and filters["until"]:
                 conditions.append(ContextEntry.created_at <= filters["until"])
             
+            # Injection filter
+            if "injection_enabled" in filters and filters["injection_enabled"] is not None:
+                conditions.append(ContextEntry.injection_enabled_clause(filters["injection_enabled"]))
+            
             # User filter
             if "user_id" in filters and filters["user_id"]:
                 conditions.append(ContextEntry.user_id == filters["user_id"])
@@ -324,6 +328,7 @@ class VaultService:
         offset: int = 0,
         context_types: Optional[List[ContextType]] = None,
         tags: Optional[List[str]] = None,
+        injection_enabled: Optional[bool] = None,
     ) -> Tuple[List[ContextEntry], int]:
         """
         Search context entries by content.
@@ -334,6 +339,7 @@ class VaultService:
             offset: Number of results to skip
             context_types: Filter by context types
             tags: Filter by tags
+            injection_enabled: Filter by whether entries may be injected
             
         Returns:
             Tuple of (matching_entries, total_count)
@@ -353,6 +359,9 @@ class VaultService:
         if tags:
             filters["tags"] = tags
         
+        if injection_enabled is not None:
+            filters["injection_enabled"] = injection_enabled
+        
         # Search with relevance ordering (simple approach)
         entries, total = self.get_context(
             filters=filters,
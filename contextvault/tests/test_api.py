@@ -1,5 +1,7 @@
 """Tests for ContextVault API endpoints."""
 
+from datetime import datetime, timedelta, timezone
+
 import pytest
 from fastapi.testclient import TestClient
 from sqlalchemy import create_engine
@@ -161,6 +163,24 @@ class TestContextEndpoints:
         assert data["content"] == "Updated content"
         assert data["tags"] == ["updated"]
     
+    def test_set_context_entry_injection(self, client, sample_context_entry):
+        """Test muting and unmuting a context entry for injection."""
+        create_response = client.post("/api/context/", json=sample_context_entry)
+        entry_id = create_response.json()["id"]
+        assert create_response.json()["injection_enabled"] is True
+
+        response = client.put(f"/api/context/{entry_id}/injection", params={"enabled": False})
+        assert response.status_code == 200
+        assert response.json()["injection_enabled"] is False
+
+        muted = client.get("/api/context/", params={"injection_enabled": False, "limit": 1000})
+        assert entry_id in [entry["id"] for entry in muted.json()["entries"]]
+
+        response = client.put(f"/api/context/{entry_id}/injection", params={"enabled": True})
+        assert response.status_code == 200
+        assert response.json()["injection_enabled"] is True
+        assert response.json()["tags"] == sample_context_entry["tags"]
+
     def test_delete_context_entry(self, client, sample_context_entry):
         """Test deleting a context entry."""
         # Create an entry
@@ -190,7 +210,73 @@ class TestContextEndpoints:
         data = response.json()
         assert "entries" in data
         assert len(data["entries"]) >= 1
-    
+
+        # A window entirely in the past matches nothing
+        response = client.get(
+            "/api/context/search/test",
+            params={"created_after": "2000-01-01T00:00:00", "created_before": "2000-02-01T00:00:00"}
+        )
+        assert response.status_code == 200
+        assert response.json()["total"] == 0
+
+    def test_get_context_entries_date_range(self, client, sample_context_entry):
+        """Test filtering context entries by creation date."""
+        client.post("/api/context/", json=sample_context_entry)
+
+        # A window entirely in the past matches nothing
+        response = client.get(
+            "/api/context/",
+            params={"created_after": "2000-01-01T00:00:00", "created_before": "2000-02-01T00:00:00"}
+        )
+        assert response.status_code == 200
+        assert response.json()["total"] == 0
+
+        # An open-ended window includes the new entry
+        response = client.get("/api/context/", params={"created_after": "2000-01-01T00:00:00"})
+        assert response.status_code == 200
+        assert response.json()["total"] >= 1
+
+        # Inverted windows are rejected
+        response = client.get(
+            "/api/context/",
+            params={"created_after": "2000-02-01T00:00:00", "created_before": "2000-01-01T00:00:00"}
+        )
+        assert response.status_code == 400
+
+    def test_get_context_entries_since_alias(self, client, sample_context_entry):
+        """Test that since behaves as an alias of created_after."""
+        client.post("/api/context/", json=sample_context_entry)
+
+        response = client.get("/api/context/", params={"since": "2000-01-01T00:00:00"})
+        assert response.status_code == 200
+        assert response.json()["total"] >= 1
+
+        # A since later than created_before is rejected like created_after
+        response = client.get(
+            "/api/context/",
+            params={"since": "2000-02-01T00:00:00", "created_before": "2000-01-01T00:00:00"}
+        )
+        assert response.status_code == 400
+
+    def test_get_context_entries_date_range_timezones(self, client, sample_context_entry):
+        """Test that timezone-aware bounds are compared in UTC."""
+        client.post("/api/context/", json=sample_context_entry)
+
+        # Mixing an aware and a naive bound is accepted
+        response = client.get(
+            "/api/context/",
+            params={"created_after": "2000-01-01T00:00:00Z", "created_before": "2000-02-01T00:00:00"}
+        )
+        assert response.status_code == 200
+        assert response.json()["total"] == 0
+
+        # An hour ago expressed in UTC+02:00 excludes entries created just now
+        one_hour_ago = datetime.now(timezone.utc) - timedelta(hours=1)
+        offset_bound = one_hour_ago.astimezone(timezone(timedelta(hours=2))).isoformat()
+        response = client.get("/api/context/", params={"created_before": offset_bound})
+        assert response.status_code == 200
+        assert response.json()["total"] == 0
+
     def test_context_stats(self, client, sample_context_entry):
         """Test getting context statistics."""
         # Create an entry
@@ -204,6 +290,9 @@ class TestContextEndpoints:
         assert "total_entries" in data
         assert "entries_by_type" in data
         assert data["total_entries"] >= 1
+        assert data["entries_this_week"] >= 1
+        assert data["entries_this_month"] >= 1
+        assert data["entries_this_week"] <= data["entries_this_month"]
     
     def test_get_tags(self, client, sample_context_entry):
         """Test getting all tags."""
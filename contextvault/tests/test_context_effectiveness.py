@@ -21,7 +21,7 @@ from contextvault.services.semantic_search import get_semantic_search_service
 from contextvault.services.debug import get_debugger, debug_context_injection
 from contextvault.services.feedback import get_feedback_service
 from contextvault.services.templates import template_manager, format_context_with_template
-from contextvault.models.context import ContextType
+from contextvault.models.context import ContextEntry, ContextType
 
 
 class TestContextInjectionEffectiveness:
@@ -81,7 +81,51 @@ class TestContextInjectionEffectiveness:
             assert len(entries) > 0, "Should find at least one relevant context entry"
             assert any("Python" in entry.content for entry in entries), "Should find Python-related context"
             assert metadata["total_found"] >= len(entries), "Metadata should be accurate"
-    
+
+    def test_muted_entries_are_never_injected(self):
+        """Test that entries with injection disabled are excluded from retrieval."""
+        query = "What programming languages should I learn?"
+
+        def set_python_entries_enabled(enabled):
+            with get_db_context() as db:
+                python_entries = db.query(ContextEntry).filter(
+                    ContextEntry.content.like("%loves Python and FastAPI%")
+                ).all()
+                for entry in python_entries:
+                    entry.set_injection_enabled(enabled)
+                db.commit()
+                return {entry.id for entry in python_entries}
+
+        def retrieved_ids():
+            with get_db_context() as db:
+                retrieval_service = ContextRetrievalService(db_session=db)
+                entries, _ = retrieval_service.get_relevant_context(
+                    model_id="test-model",
+                    query_context=query,
+                    limit=5
+                )
+                prompt_context = retrieval_service.get_context_for_prompt(
+                    model_id="test-model",
+                    user_prompt=query
+                )
+                return (
+                    {entry.id for entry in entries},
+                    {entry["id"] for entry in prompt_context["context_entries"]},
+                )
+
+        muted_ids = set_python_entries_enabled(False)
+        assert muted_ids, "Fixture should provide entries to mute"
+        try:
+            retrieved, injected = retrieved_ids()
+            assert not muted_ids & retrieved, "Muted entries should not be retrieved"
+            assert not muted_ids & injected, "Muted entries should not be injected"
+        finally:
+            set_python_entries_enabled(True)
+
+        retrieved, injected = retrieved_ids()
+        assert muted_ids & retrieved, "Re-enabled entries should be retrieved again"
+        assert muted_ids & injected, "Re-enabled entries should be injected again"
+
     def test_semantic_search_returns_relevant_results(self):
         """Test that semantic search returns relevant results."""
         semantic_service = get_semantic_search_service()